@@ -0,0 +1,95 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{Binary, Deps, DepsMut, Env, MessageInfo, Reply, Response};
+
+use crate::{
+    error::ContractResult,
+    execute::{execute_swap_exact_asset_in, execute_swap_exact_asset_out, execute_transfer_funds_back},
+    msg::{ExecuteMsg, InstantiateMsg, QueryMsg},
+    reply::handle_swap_operation_reply,
+    state::{ENTRY_POINT_CONTRACT_ADDRESS, ROUTER_CONTRACT_ADDRESS},
+};
+
+pub const REPLY_ID_SWAP_OPERATION: u64 = 1;
+
+// The custom query type this contract is compiled against. Defaults to the
+// chain-agnostic `Empty`; a deployment targeting a chain whose assets live
+// behind a custom bank/smart-token module swaps this for that chain SDK's
+// custom query enum (e.g. `sei_cosmwasm::SeiQueryWrapper`) behind a feature
+// flag, without touching the adapter logic below.
+#[cfg(not(feature = "sei"))]
+pub type QueryT = cosmwasm_std::Empty;
+#[cfg(feature = "sei")]
+pub type QueryT = sei_cosmwasm::SeiQueryWrapper;
+
+///////////////
+/// INSTANTIATE
+///////////////
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut<QueryT>,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> ContractResult<Response> {
+    let entry_point_contract_address =
+        deps.api.addr_validate(&msg.entry_point_contract_address)?;
+    let router_contract_address = deps.api.addr_validate(&msg.router_contract_address)?;
+
+    ENTRY_POINT_CONTRACT_ADDRESS.save(deps.storage, &entry_point_contract_address)?;
+    ROUTER_CONTRACT_ADDRESS.save(deps.storage, &router_contract_address)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "instantiate")
+        .add_attribute(
+            "entry_point_contract_address",
+            entry_point_contract_address,
+        )
+        .add_attribute("router_contract_address", router_contract_address))
+}
+
+///////////////
+/// EXECUTE
+///////////////
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut<QueryT>,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> ContractResult<Response> {
+    match msg {
+        ExecuteMsg::Swap { operations } => execute_swap_exact_asset_in(deps, env, info, operations),
+        ExecuteMsg::SwapExactAssetOut {
+            operations,
+            asset_out,
+        } => execute_swap_exact_asset_out(deps, env, info, operations, asset_out),
+        ExecuteMsg::TransferFundsBack {
+            swapper,
+            return_denom,
+        } => execute_transfer_funds_back(deps, env, info, swapper, return_denom),
+    }
+}
+
+///////////////
+/// QUERY
+///////////////
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps<QueryT>, env: Env, msg: QueryMsg) -> ContractResult<Binary> {
+    crate::query::query(deps, env, msg)
+}
+
+///////////////
+/// REPLY
+///////////////
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(deps: DepsMut<QueryT>, env: Env, msg: Reply) -> ContractResult<Response> {
+    match msg.id {
+        REPLY_ID_SWAP_OPERATION => handle_swap_operation_reply(deps, env, msg),
+        id => Err(cosmwasm_std::StdError::generic_err(format!("unknown reply id: {id}")).into()),
+    }
+}
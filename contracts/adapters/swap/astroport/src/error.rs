@@ -0,0 +1,23 @@
+use cosmwasm_std::StdError;
+use cw_utils::PaymentError;
+use thiserror::Error;
+
+pub type ContractResult<T> = Result<T, ContractError>;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("{0}")]
+    Payment(#[from] PaymentError),
+
+    #[error("Unauthorized")]
+    Unauthorized,
+
+    #[error("Swap operations cannot be empty")]
+    SwapOperationsEmpty,
+
+    #[error("Sent funds do not match the amount required for the requested exact asset out")]
+    InvalidSwapOperationAmount,
+}
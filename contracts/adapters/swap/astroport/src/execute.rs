@@ -0,0 +1,161 @@
+use std::str::FromStr;
+
+use cosmwasm_std::{
+    to_binary, Addr, BankMsg, Coin, CustomQuery, Decimal, Deps, DepsMut, Env, MessageInfo,
+    Response, SubMsg, WasmMsg,
+};
+use cw_utils::one_coin;
+use skip::{asset::Asset, swap::SwapOperation};
+
+use crate::{
+    contract::REPLY_ID_SWAP_OPERATION,
+    error::{ContractError, ContractResult},
+    query::simulate_swap_exact_asset_out,
+    state::{
+        SwapOperationState, ENTRY_POINT_CONTRACT_ADDRESS, MAX_ALLOWED_SLIPPAGE,
+        SWAP_OPERATION_STATE,
+    },
+};
+
+pub fn execute_swap_exact_asset_in<C: CustomQuery>(
+    deps: DepsMut<C>,
+    env: Env,
+    info: MessageInfo,
+    operations: Vec<SwapOperation>,
+) -> ContractResult<Response> {
+    only_entry_point(deps.as_ref(), &info)?;
+
+    let coin = one_coin(&info)?;
+
+    start_swap_operations(deps, env, info.sender, operations, coin, "swap_exact_asset_in")
+}
+
+pub fn execute_swap_exact_asset_out<C: CustomQuery>(
+    deps: DepsMut<C>,
+    env: Env,
+    info: MessageInfo,
+    operations: Vec<SwapOperation>,
+    asset_out: Asset,
+) -> ContractResult<Response> {
+    only_entry_point(deps.as_ref(), &info)?;
+
+    let coin = one_coin(&info)?;
+
+    // The entry point is expected to have queried `SimulateSwapExactAssetOut`
+    // beforehand and sent exactly the computed input amount.
+    let required_asset_in = simulate_swap_exact_asset_out(deps.as_ref(), asset_out, &operations)?;
+    if coin.amount != required_asset_in.amount() || coin.denom != required_asset_in.denom() {
+        return Err(ContractError::InvalidSwapOperationAmount);
+    }
+
+    start_swap_operations(deps, env, info.sender, operations, coin, "swap_exact_asset_out")
+}
+
+pub fn execute_transfer_funds_back<C: CustomQuery>(
+    deps: DepsMut<C>,
+    env: Env,
+    info: MessageInfo,
+    swapper: Addr,
+    return_denom: String,
+) -> ContractResult<Response> {
+    if info.sender != env.contract.address {
+        return Err(ContractError::Unauthorized);
+    }
+
+    let balance = Asset::Native(Coin {
+        denom: return_denom.clone(),
+        amount: cosmwasm_std::Uint128::zero(),
+    })
+    .query_balance(&deps.querier, &env.contract.address)?;
+
+    let mut response = Response::new().add_attribute("action", "transfer_funds_back");
+
+    if !balance.is_zero() {
+        response = response.add_message(BankMsg::Send {
+            to_address: swapper.to_string(),
+            amount: vec![Coin {
+                denom: return_denom,
+                amount: balance,
+            }],
+        });
+    }
+
+    Ok(response)
+}
+
+fn only_entry_point<C: CustomQuery>(deps: Deps<C>, info: &MessageInfo) -> ContractResult<()> {
+    let entry_point_contract_address = ENTRY_POINT_CONTRACT_ADDRESS.load(deps.storage)?;
+    if info.sender != entry_point_contract_address {
+        return Err(ContractError::Unauthorized);
+    }
+
+    Ok(())
+}
+
+// Fires the first hop of `operations` against its pair contract, chaining
+// the remaining hops through `reply::handle_swap_operation_reply` since
+// each hop's exact output is only known once it executes.
+fn start_swap_operations<C: CustomQuery>(
+    deps: DepsMut<C>,
+    env: Env,
+    swapper: Addr,
+    mut operations: Vec<SwapOperation>,
+    offer_coin: Coin,
+    action: &str,
+) -> ContractResult<Response> {
+    if operations.is_empty() {
+        return Err(ContractError::SwapOperationsEmpty);
+    }
+
+    let operation = operations.remove(0);
+    let swap_msg = build_pair_swap_msg(&operation, offer_coin)?;
+
+    if operations.is_empty() {
+        let transfer_funds_back_msg = SubMsg::new(WasmMsg::Execute {
+            contract_addr: env.contract.address.to_string(),
+            msg: to_binary(&skip::swap::ExecuteMsg::TransferFundsBack {
+                swapper,
+                return_denom: operation.denom_out,
+            })?,
+            funds: vec![],
+        });
+
+        return Ok(Response::new()
+            .add_submessage(SubMsg::new(swap_msg))
+            .add_submessage(transfer_funds_back_msg)
+            .add_attribute("action", action));
+    }
+
+    SWAP_OPERATION_STATE.save(
+        deps.storage,
+        &SwapOperationState {
+            remaining_operations: operations,
+            swapper,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_submessage(SubMsg::reply_on_success(swap_msg, REPLY_ID_SWAP_OPERATION))
+        .add_attribute("action", action))
+}
+
+// Builds the `WasmMsg::Execute` that swaps `offer_coin` on `operation.pool`,
+// bounding slippage to `MAX_ALLOWED_SLIPPAGE`.
+pub fn build_pair_swap_msg(operation: &SwapOperation, offer_coin: Coin) -> ContractResult<WasmMsg> {
+    Ok(WasmMsg::Execute {
+        contract_addr: operation.pool.clone(),
+        msg: to_binary(&astroport::pair::ExecuteMsg::Swap {
+            offer_asset: astroport::asset::Asset {
+                info: astroport::asset::AssetInfo::NativeToken {
+                    denom: offer_coin.denom.clone(),
+                },
+                amount: offer_coin.amount,
+            },
+            belief_price: None,
+            max_spread: Some(Decimal::from_str(MAX_ALLOWED_SLIPPAGE).unwrap()),
+            to: None,
+            ask_asset_info: None,
+        })?,
+        funds: vec![offer_coin],
+    })
+}
@@ -0,0 +1,6 @@
+use cosmwasm_schema::cw_serde;
+
+pub use skip::swap::{AstroportInstantiateMsg as InstantiateMsg, ExecuteMsg, QueryMsg};
+
+#[cw_serde]
+pub struct MigrateMsg {}
@@ -0,0 +1,106 @@
+use cosmwasm_std::{to_binary, Binary, Coin, CustomQuery, Deps, Env};
+use skip::{asset::Asset, swap::SwapOperation};
+
+use crate::{
+    error::{ContractError, ContractResult},
+    msg::QueryMsg,
+};
+
+pub fn query<C: CustomQuery>(deps: Deps<C>, _env: Env, msg: QueryMsg) -> ContractResult<Binary> {
+    let res = match msg {
+        QueryMsg::SimulateSwapExactAssetIn {
+            asset_in,
+            swap_operations,
+        } => to_binary(&simulate_swap_exact_asset_in(deps, asset_in, &swap_operations)?)?,
+        QueryMsg::SimulateSwapExactAssetOut {
+            asset_out,
+            swap_operations,
+        } => to_binary(&simulate_swap_exact_asset_out(
+            deps,
+            asset_out,
+            &swap_operations,
+        )?)?,
+    };
+
+    Ok(res)
+}
+
+// Walks `swap_operations` forward, pricing each hop with the pair's
+// `Simulation` query and feeding its `return_amount` into the next hop.
+pub fn simulate_swap_exact_asset_in<C: CustomQuery>(
+    deps: Deps<C>,
+    asset_in: Asset,
+    swap_operations: &[SwapOperation],
+) -> ContractResult<Asset> {
+    if swap_operations.is_empty() {
+        return Err(ContractError::SwapOperationsEmpty);
+    }
+
+    let mut offer_coin = Coin {
+        denom: asset_in.denom(),
+        amount: asset_in.amount(),
+    };
+
+    for operation in swap_operations {
+        let simulation: astroport::pair::SimulationResponse = deps.querier.query_wasm_smart(
+            &operation.pool,
+            &astroport::pair::QueryMsg::Simulation {
+                offer_asset: astroport::asset::Asset {
+                    info: astroport::asset::AssetInfo::NativeToken {
+                        denom: offer_coin.denom.clone(),
+                    },
+                    amount: offer_coin.amount,
+                },
+                ask_asset_info: None,
+            },
+        )?;
+
+        offer_coin = Coin {
+            denom: operation.denom_out.clone(),
+            amount: simulation.return_amount,
+        };
+    }
+
+    Ok(Asset::Native(offer_coin))
+}
+
+// Walks `swap_operations` in reverse, pricing each hop with the pair's
+// `ReverseSimulation` query and accumulating the required input back to
+// the first hop.
+pub fn simulate_swap_exact_asset_out<C: CustomQuery>(
+    deps: Deps<C>,
+    asset_out: Asset,
+    swap_operations: &[SwapOperation],
+) -> ContractResult<Asset> {
+    if swap_operations.is_empty() {
+        return Err(ContractError::SwapOperationsEmpty);
+    }
+
+    let mut ask_coin = Coin {
+        denom: asset_out.denom(),
+        amount: asset_out.amount(),
+    };
+
+    for operation in swap_operations.iter().rev() {
+        let reverse_simulation: astroport::pair::ReverseSimulationResponse =
+            deps.querier.query_wasm_smart(
+                &operation.pool,
+                &astroport::pair::QueryMsg::ReverseSimulation {
+                    ask_asset: astroport::asset::Asset {
+                        info: astroport::asset::AssetInfo::NativeToken {
+                            denom: ask_coin.denom.clone(),
+                        },
+                        amount: ask_coin.amount,
+                    },
+                    offer_asset_info: None,
+                },
+            )?;
+
+        ask_coin = Coin {
+            denom: operation.denom_in.clone(),
+            amount: reverse_simulation.offer_amount,
+        };
+    }
+
+    Ok(Asset::Native(ask_coin))
+}
@@ -0,0 +1,69 @@
+use cosmwasm_std::{to_binary, Coin, CustomQuery, DepsMut, Env, Reply, Response, StdError, SubMsg, WasmMsg};
+use skip::asset::Asset;
+
+use crate::{
+    contract::REPLY_ID_SWAP_OPERATION,
+    error::ContractResult,
+    execute::build_pair_swap_msg,
+    state::{SwapOperationState, SWAP_OPERATION_STATE},
+};
+
+// Fires once the previous hop's pair swap has executed: reads the amount it
+// actually produced off this contract's own balance, then either chains
+// into the next hop or hands the final output to `TransferFundsBack`.
+pub fn handle_swap_operation_reply<C: CustomQuery>(
+    deps: DepsMut<C>,
+    env: Env,
+    msg: Reply,
+) -> ContractResult<Response> {
+    msg.result.into_result().map_err(StdError::generic_err)?;
+
+    let SwapOperationState {
+        mut remaining_operations,
+        swapper,
+    } = SWAP_OPERATION_STATE.load(deps.storage)?;
+    SWAP_OPERATION_STATE.remove(deps.storage);
+
+    let operation = remaining_operations.remove(0);
+
+    let balance = Asset::Native(Coin {
+        denom: operation.denom_in.clone(),
+        amount: cosmwasm_std::Uint128::zero(),
+    })
+    .query_balance(&deps.querier, &env.contract.address)?;
+
+    let offer_coin = Coin {
+        denom: operation.denom_in.clone(),
+        amount: balance,
+    };
+
+    let swap_msg = build_pair_swap_msg(&operation, offer_coin)?;
+
+    if remaining_operations.is_empty() {
+        let transfer_funds_back_msg = SubMsg::new(WasmMsg::Execute {
+            contract_addr: env.contract.address.to_string(),
+            msg: to_binary(&skip::swap::ExecuteMsg::TransferFundsBack {
+                swapper,
+                return_denom: operation.denom_out,
+            })?,
+            funds: vec![],
+        });
+
+        return Ok(Response::new()
+            .add_submessage(SubMsg::new(swap_msg))
+            .add_submessage(transfer_funds_back_msg)
+            .add_attribute("action", "swap_operation_reply"));
+    }
+
+    SWAP_OPERATION_STATE.save(
+        deps.storage,
+        &SwapOperationState {
+            remaining_operations,
+            swapper,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_submessage(SubMsg::reply_on_success(swap_msg, REPLY_ID_SWAP_OPERATION))
+        .add_attribute("action", "swap_operation_reply"))
+}
@@ -0,0 +1,23 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::Addr;
+use cw_storage_plus::Item;
+use skip::swap::SwapOperation;
+
+pub const ENTRY_POINT_CONTRACT_ADDRESS: Item<Addr> = Item::new("entry_point_contract_address");
+pub const ROUTER_CONTRACT_ADDRESS: Item<Addr> = Item::new("router_contract_address");
+
+// The maximum spread (as a decimal string) allowed on any single pair swap,
+// whether the amount in was given directly or derived from a reverse
+// simulation for an exact-out swap.
+pub const MAX_ALLOWED_SLIPPAGE: &str = "0.05";
+
+// Carries the remaining hops of a multi-pool swap across the reply that
+// follows each individual pair swap, since the exact output of a hop is
+// only known once it has executed.
+pub const SWAP_OPERATION_STATE: Item<SwapOperationState> = Item::new("swap_operation_state");
+
+#[cw_serde]
+pub struct SwapOperationState {
+    pub remaining_operations: Vec<SwapOperation>,
+    pub swapper: Addr,
+}
@@ -0,0 +1,122 @@
+use cosmwasm_std::{
+    testing::{mock_dependencies, MockQuerier},
+    to_binary, Coin, ContractResult, OwnedDeps, SystemResult, Uint128,
+};
+use skip::{asset::Asset, swap::SwapOperation};
+use skip_api_swap_adapter_astroport::query::{
+    simulate_swap_exact_asset_in, simulate_swap_exact_asset_out,
+};
+
+// Wires up a mock querier that answers Astroport `Simulation` /
+// `ReverseSimulation` queries against two pools: `pool_a` trading
+// `uatom` -> `uosmo` at a flat 5% spread, and `pool_b` trading
+// `uosmo` -> `uion` at a flat 2% spread.
+fn mock_deps_with_pools() -> OwnedDeps<
+    cosmwasm_std::testing::MockStorage,
+    cosmwasm_std::testing::MockApi,
+    MockQuerier,
+> {
+    let mut deps = mock_dependencies();
+
+    deps.querier.update_wasm(|query| match query {
+        cosmwasm_std::WasmQuery::Smart { contract_addr, msg } => {
+            let spread_bps: u128 = match contract_addr.as_str() {
+                "pool_a" => 500,
+                "pool_b" => 200,
+                other => panic!("unexpected contract address: {other}"),
+            };
+
+            if let Ok(astroport::pair::QueryMsg::Simulation { offer_asset, .. }) =
+                cosmwasm_std::from_binary(msg)
+            {
+                let return_amount = offer_asset.amount
+                    - offer_asset.amount.multiply_ratio(spread_bps, 10_000u128);
+                return SystemResult::Ok(ContractResult::Ok(
+                    to_binary(&astroport::pair::SimulationResponse {
+                        return_amount,
+                        spread_amount: offer_asset.amount - return_amount,
+                        commission_amount: Uint128::zero(),
+                    })
+                    .unwrap(),
+                ));
+            }
+
+            if let Ok(astroport::pair::QueryMsg::ReverseSimulation { ask_asset, .. }) =
+                cosmwasm_std::from_binary(msg)
+            {
+                let offer_amount = ask_asset.amount
+                    + ask_asset.amount.multiply_ratio(spread_bps, 10_000u128 - spread_bps);
+                return SystemResult::Ok(ContractResult::Ok(
+                    to_binary(&astroport::pair::ReverseSimulationResponse {
+                        offer_amount,
+                        spread_amount: offer_amount - ask_asset.amount,
+                        commission_amount: Uint128::zero(),
+                    })
+                    .unwrap(),
+                ));
+            }
+
+            panic!("unexpected query")
+        }
+        _ => panic!("unexpected query type"),
+    });
+
+    deps
+}
+
+fn operations() -> Vec<SwapOperation> {
+    vec![
+        SwapOperation {
+            pool: "pool_a".to_string(),
+            denom_in: "uatom".to_string(),
+            denom_out: "uosmo".to_string(),
+        },
+        SwapOperation {
+            pool: "pool_b".to_string(),
+            denom_in: "uosmo".to_string(),
+            denom_out: "uion".to_string(),
+        },
+    ]
+}
+
+#[test]
+fn simulate_swap_exact_asset_in_walks_forward_through_every_hop() {
+    let deps = mock_deps_with_pools();
+
+    let asset_in = Asset::Native(Coin::new(1_000_000, "uatom"));
+    let asset_out =
+        simulate_swap_exact_asset_in(deps.as_ref(), asset_in, &operations()).unwrap();
+
+    assert_eq!(asset_out.denom(), "uion");
+    assert!(asset_out.amount() < Uint128::new(1_000_000));
+}
+
+#[test]
+fn simulate_swap_exact_asset_out_walks_backward_through_every_hop() {
+    let deps = mock_deps_with_pools();
+
+    let asset_out = Asset::Native(Coin::new(1_000_000, "uion"));
+    let asset_in =
+        simulate_swap_exact_asset_out(deps.as_ref(), asset_out, &operations()).unwrap();
+
+    assert_eq!(asset_in.denom(), "uatom");
+    assert!(asset_in.amount() > Uint128::new(1_000_000));
+}
+
+#[test]
+fn simulate_swap_exact_asset_out_then_in_round_trips_within_fee_bounds() {
+    let deps = mock_deps_with_pools();
+
+    let desired_out = Asset::Native(Coin::new(1_000_000, "uion"));
+    let required_in =
+        simulate_swap_exact_asset_out(deps.as_ref(), desired_out, &operations()).unwrap();
+
+    let resulting_out =
+        simulate_swap_exact_asset_in(deps.as_ref(), required_in, &operations()).unwrap();
+
+    // Forward and reverse simulations aren't perfectly symmetric once both
+    // hops' spreads compound, but they should land close to the requested
+    // output rather than wildly off.
+    let diff = resulting_out.amount().abs_diff(Uint128::new(1_000_000));
+    assert!(diff < Uint128::new(10_000));
+}
@@ -0,0 +1,260 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{
+    to_binary, Addr, BankMsg, Binary, Coin, CustomQuery, Deps, DepsMut, Env, MessageInfo,
+    Response, SubMsg, WasmMsg,
+};
+use cw_utils::one_coin;
+use lido_satellite::msg::ExecuteMsg as LidoSatelliteExecuteMsg;
+use skip::asset::Asset;
+use skip::swap::{ExecuteMsg, QueryMsg, SwapOperation};
+
+use crate::{
+    error::{ContractError, ContractResult},
+    msg::InstantiateMsg,
+    state::{
+        BRIDGED_DENOM, CANONICAL_DENOM, ENTRY_POINT_CONTRACT_ADDRESS,
+        LIDO_SATELLITE_CONTRACT_ADDRESS,
+    },
+};
+
+// The custom query type this contract is compiled against. Defaults to the
+// chain-agnostic `Empty`; a deployment targeting a chain whose assets live
+// behind a custom bank/smart-token module swaps this for that chain SDK's
+// custom query enum (e.g. `sei_cosmwasm::SeiQueryWrapper`) behind a feature
+// flag, without touching the adapter logic below.
+#[cfg(not(feature = "sei"))]
+pub type QueryT = cosmwasm_std::Empty;
+#[cfg(feature = "sei")]
+pub type QueryT = sei_cosmwasm::SeiQueryWrapper;
+
+///////////////
+/// INSTANTIATE
+///////////////
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut<QueryT>,
+    _env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> ContractResult<Response> {
+    let entry_point_contract_address =
+        deps.api.addr_validate(&msg.entry_point_contract_address)?;
+    let lido_satellite_contract_address =
+        deps.api.addr_validate(&msg.lido_satellite_contract_address)?;
+
+    ENTRY_POINT_CONTRACT_ADDRESS.save(deps.storage, &entry_point_contract_address)?;
+    LIDO_SATELLITE_CONTRACT_ADDRESS.save(deps.storage, &lido_satellite_contract_address)?;
+    BRIDGED_DENOM.save(deps.storage, &msg.bridged_denom)?;
+    CANONICAL_DENOM.save(deps.storage, &msg.canonical_denom)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "instantiate")
+        .add_attribute(
+            "entry_point_contract_address",
+            entry_point_contract_address,
+        )
+        .add_attribute(
+            "lido_satellite_contract_address",
+            lido_satellite_contract_address,
+        )
+        .add_attribute("bridged_denom", msg.bridged_denom)
+        .add_attribute("canonical_denom", msg.canonical_denom))
+}
+
+///////////////
+/// EXECUTE
+///////////////
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut<QueryT>,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> ContractResult<Response> {
+    match msg {
+        ExecuteMsg::Swap { operations } => execute_swap(deps, env, info, operations),
+        ExecuteMsg::SwapExactAssetOut { .. } => Err(ContractError::SwapExactAssetOutUnsupported),
+        ExecuteMsg::TransferFundsBack {
+            swapper,
+            return_denom,
+        } => execute_transfer_funds_back(deps, env, info, swapper, return_denom),
+    }
+}
+
+fn execute_swap<C: CustomQuery>(
+    deps: DepsMut<C>,
+    env: Env,
+    info: MessageInfo,
+    _operations: Vec<SwapOperation>,
+) -> ContractResult<Response> {
+    // Only the entry point contract is allowed to call this function
+    let entry_point_contract_address = ENTRY_POINT_CONTRACT_ADDRESS.load(deps.storage)?;
+    if info.sender != entry_point_contract_address {
+        return Err(ContractError::Unauthorized);
+    }
+
+    // Enforce exactly one coin was sent
+    let coin = one_coin(&info)?;
+
+    let bridged_denom = BRIDGED_DENOM.load(deps.storage)?;
+    let canonical_denom = CANONICAL_DENOM.load(deps.storage)?;
+    let lido_satellite_contract_address = LIDO_SATELLITE_CONTRACT_ADDRESS.load(deps.storage)?;
+
+    let (lido_satellite_msg, return_denom) = if coin.denom == bridged_denom {
+        (
+            LidoSatelliteExecuteMsg::Mint { receiver: None },
+            canonical_denom,
+        )
+    } else if coin.denom == canonical_denom {
+        (
+            LidoSatelliteExecuteMsg::Burn { receiver: None },
+            bridged_denom,
+        )
+    } else {
+        return Err(ContractError::UnsupportedDenom);
+    };
+
+    let lido_satellite_msg = SubMsg::new(WasmMsg::Execute {
+        contract_addr: lido_satellite_contract_address.to_string(),
+        msg: to_binary(&lido_satellite_msg)?,
+        funds: vec![coin],
+    });
+
+    let transfer_funds_back_msg = SubMsg::new(WasmMsg::Execute {
+        contract_addr: env.contract.address.to_string(),
+        msg: to_binary(&ExecuteMsg::TransferFundsBack {
+            swapper: info.sender,
+            return_denom,
+        })?,
+        funds: vec![],
+    });
+
+    Ok(Response::new()
+        .add_submessage(lido_satellite_msg)
+        .add_submessage(transfer_funds_back_msg)
+        .add_attribute("action", "execute_swap"))
+}
+
+// Forwards this contract's entire balance of `return_denom` to `swapper`.
+// Only callable by the contract itself, as the second leg of `execute_swap`.
+fn execute_transfer_funds_back<C: CustomQuery>(
+    deps: DepsMut<C>,
+    env: Env,
+    info: MessageInfo,
+    swapper: Addr,
+    return_denom: String,
+) -> ContractResult<Response> {
+    if info.sender != env.contract.address {
+        return Err(ContractError::Unauthorized);
+    }
+
+    let balance = Asset::Native(Coin {
+        denom: return_denom.clone(),
+        amount: cosmwasm_std::Uint128::zero(),
+    })
+    .query_balance(&deps.querier, &env.contract.address)?;
+
+    let mut response = Response::new().add_attribute("action", "transfer_funds_back");
+
+    if !balance.is_zero() {
+        response = response.add_message(BankMsg::Send {
+            to_address: swapper.to_string(),
+            amount: vec![Coin {
+                denom: return_denom,
+                amount: balance,
+            }],
+        });
+    }
+
+    Ok(response)
+}
+
+///////////////
+/// QUERY
+///////////////
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn query(deps: Deps<QueryT>, _env: Env, msg: QueryMsg) -> ContractResult<Binary> {
+    let res = match msg {
+        QueryMsg::SimulateSwapExactAssetIn {
+            asset_in,
+            swap_operations,
+        } => to_binary(&simulate_swap_exact_asset_in(
+            deps,
+            asset_in,
+            &swap_operations,
+        )?)?,
+        QueryMsg::SimulateSwapExactAssetOut {
+            asset_out,
+            swap_operations,
+        } => to_binary(&simulate_swap_exact_asset_out(
+            deps,
+            asset_out,
+            &swap_operations,
+        )?)?,
+    };
+
+    Ok(res)
+}
+
+// Validates that `swap_operations` contains the single Mint/Burn hop this
+// adapter supports, and returns it.
+fn validate_swap_operation<'a, C: CustomQuery>(
+    deps: Deps<C>,
+    swap_operations: &'a [SwapOperation],
+) -> ContractResult<&'a SwapOperation> {
+    let [operation] = swap_operations else {
+        return Err(ContractError::UnsupportedSwapOperations);
+    };
+
+    let bridged_denom = BRIDGED_DENOM.load(deps.storage)?;
+    let canonical_denom = CANONICAL_DENOM.load(deps.storage)?;
+
+    let is_mint = operation.denom_in == bridged_denom && operation.denom_out == canonical_denom;
+    let is_burn = operation.denom_in == canonical_denom && operation.denom_out == bridged_denom;
+
+    if !is_mint && !is_burn {
+        return Err(ContractError::UnsupportedDenom);
+    }
+
+    Ok(operation)
+}
+
+fn simulate_swap_exact_asset_in<C: CustomQuery>(
+    deps: Deps<C>,
+    asset_in: Asset,
+    swap_operations: &[SwapOperation],
+) -> ContractResult<Asset> {
+    let operation = validate_swap_operation(deps, swap_operations)?;
+
+    if asset_in.denom() != operation.denom_in {
+        return Err(ContractError::UnsupportedDenom);
+    }
+
+    // Mint/Burn against BRIDGED_DENOM <-> CANONICAL_DENOM is always 1:1
+    Ok(Asset::Native(Coin {
+        denom: operation.denom_out.clone(),
+        amount: asset_in.amount(),
+    }))
+}
+
+fn simulate_swap_exact_asset_out<C: CustomQuery>(
+    deps: Deps<C>,
+    asset_out: Asset,
+    swap_operations: &[SwapOperation],
+) -> ContractResult<Asset> {
+    let operation = validate_swap_operation(deps, swap_operations)?;
+
+    if asset_out.denom() != operation.denom_out {
+        return Err(ContractError::UnsupportedDenom);
+    }
+
+    // Mint/Burn against BRIDGED_DENOM <-> CANONICAL_DENOM is always 1:1
+    Ok(Asset::Native(Coin {
+        denom: operation.denom_in.clone(),
+        amount: asset_out.amount(),
+    }))
+}
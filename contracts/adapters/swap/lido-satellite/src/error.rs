@@ -0,0 +1,26 @@
+use cosmwasm_std::StdError;
+use cw_utils::PaymentError;
+use thiserror::Error;
+
+pub type ContractResult<T> = Result<T, ContractError>;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("{0}")]
+    Payment(#[from] PaymentError),
+
+    #[error("Unauthorized")]
+    Unauthorized,
+
+    #[error("Unsupported denom")]
+    UnsupportedDenom,
+
+    #[error("Swap operations must contain exactly one operation")]
+    UnsupportedSwapOperations,
+
+    #[error("Exact asset out swaps are not supported")]
+    SwapExactAssetOutUnsupported,
+}
@@ -0,0 +1,14 @@
+use cosmwasm_schema::cw_serde;
+
+pub use skip::swap::{ExecuteMsg, QueryMsg};
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub entry_point_contract_address: String,
+    pub lido_satellite_contract_address: String,
+    pub bridged_denom: String,
+    pub canonical_denom: String,
+}
+
+#[cw_serde]
+pub struct MigrateMsg {}
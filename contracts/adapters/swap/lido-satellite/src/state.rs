@@ -0,0 +1,15 @@
+use cosmwasm_std::Addr;
+use cw_storage_plus::Item;
+
+// The entry point contract address that is allowed to call `ExecuteMsg::Swap`
+pub const ENTRY_POINT_CONTRACT_ADDRESS: Item<Addr> = Item::new("entry_point_contract_address");
+
+// The Lido Satellite contract that mints/burns the canonical denom
+pub const LIDO_SATELLITE_CONTRACT_ADDRESS: Item<Addr> =
+    Item::new("lido_satellite_contract_address");
+
+// The IBC-bridged denom (e.g. `ibc/wstETH`), burned to mint `CANONICAL_DENOM`
+pub const BRIDGED_DENOM: Item<String> = Item::new("bridged_denom");
+
+// The canonical token-factory denom (e.g. `factory/wstETH`), minted from `BRIDGED_DENOM`
+pub const CANONICAL_DENOM: Item<String> = Item::new("canonical_denom");
@@ -0,0 +1,141 @@
+use cosmwasm_std::{
+    testing::{mock_dependencies, mock_env},
+    Coin,
+};
+use skip::{asset::Asset, swap::SwapOperation};
+use skip_api_swap_adapter_lido_satellite::{
+    error::ContractError,
+    state::{BRIDGED_DENOM, CANONICAL_DENOM, ENTRY_POINT_CONTRACT_ADDRESS},
+};
+use test_case::test_case;
+
+/*
+Test Cases:
+
+Expect Success
+    - 'SimulateSwapExactAssetIn' Mint direction
+    - 'SimulateSwapExactAssetOut' Burn direction
+
+Expect Error
+    - Unsupported denom
+    - More than one swap operation
+
+ */
+
+struct Params {
+    asset_in: Option<Asset>,
+    asset_out: Option<Asset>,
+    swap_operations: Vec<SwapOperation>,
+    expected_asset: Option<Asset>,
+    expected_error: Option<ContractError>,
+}
+
+#[test_case(
+    Params {
+        asset_in: Some(Asset::Native(Coin::new(100, "ibc/wstETH"))),
+        asset_out: None,
+        swap_operations: vec![SwapOperation {
+            pool: "lido_satellite_contract".to_string(),
+            denom_in: "ibc/wstETH".to_string(),
+            denom_out: "factory/wstETH".to_string(),
+        }],
+        expected_asset: Some(Asset::Native(Coin::new(100, "factory/wstETH"))),
+        expected_error: None,
+    };
+    "SimulateSwapExactAssetIn - Mint direction")]
+#[test_case(
+    Params {
+        asset_in: Some(Asset::Native(Coin::new(100, "uosmo"))),
+        asset_out: None,
+        swap_operations: vec![SwapOperation {
+            pool: "lido_satellite_contract".to_string(),
+            denom_in: "ibc/wstETH".to_string(),
+            denom_out: "factory/wstETH".to_string(),
+        }],
+        expected_asset: None,
+        expected_error: Some(ContractError::UnsupportedDenom),
+    };
+    "SimulateSwapExactAssetIn - Unsupported denom")]
+#[test_case(
+    Params {
+        asset_in: Some(Asset::Native(Coin::new(100, "ibc/wstETH"))),
+        asset_out: None,
+        swap_operations: vec![
+            SwapOperation {
+                pool: "lido_satellite_contract".to_string(),
+                denom_in: "ibc/wstETH".to_string(),
+                denom_out: "factory/wstETH".to_string(),
+            },
+            SwapOperation {
+                pool: "lido_satellite_contract".to_string(),
+                denom_in: "factory/wstETH".to_string(),
+                denom_out: "ibc/wstETH".to_string(),
+            },
+        ],
+        expected_asset: None,
+        expected_error: Some(ContractError::UnsupportedSwapOperations),
+    };
+    "SimulateSwapExactAssetIn - More than one swap operation")]
+#[test_case(
+    Params {
+        asset_in: None,
+        asset_out: Some(Asset::Native(Coin::new(100, "ibc/wstETH"))),
+        swap_operations: vec![SwapOperation {
+            pool: "lido_satellite_contract".to_string(),
+            denom_in: "factory/wstETH".to_string(),
+            denom_out: "ibc/wstETH".to_string(),
+        }],
+        expected_asset: Some(Asset::Native(Coin::new(100, "factory/wstETH"))),
+        expected_error: None,
+    };
+    "SimulateSwapExactAssetOut - Burn direction")]
+fn test_simulate_swap(params: Params) {
+    let mut deps = mock_dependencies();
+    let env = mock_env();
+
+    ENTRY_POINT_CONTRACT_ADDRESS
+        .save(deps.as_mut().storage, &cosmwasm_std::Addr::unchecked("entry_point"))
+        .unwrap();
+    BRIDGED_DENOM
+        .save(deps.as_mut().storage, &String::from("ibc/wstETH"))
+        .unwrap();
+    CANONICAL_DENOM
+        .save(deps.as_mut().storage, &String::from("factory/wstETH"))
+        .unwrap();
+
+    let msg = match (params.asset_in, params.asset_out) {
+        (Some(asset_in), None) => skip::swap::QueryMsg::SimulateSwapExactAssetIn {
+            asset_in,
+            swap_operations: params.swap_operations,
+        },
+        (None, Some(asset_out)) => skip::swap::QueryMsg::SimulateSwapExactAssetOut {
+            asset_out,
+            swap_operations: params.swap_operations,
+        },
+        _ => panic!("test must set exactly one of asset_in/asset_out"),
+    };
+
+    let res = skip_api_swap_adapter_lido_satellite::contract::query(deps.as_ref(), env, msg);
+
+    match res {
+        Ok(res) => {
+            assert!(
+                params.expected_error.is_none(),
+                "expected test to error with {:?}, but it succeeded",
+                params.expected_error
+            );
+
+            let asset: Asset = cosmwasm_std::from_binary(res).unwrap();
+            assert_eq!(asset, params.expected_asset.unwrap());
+        }
+        Err(err) => {
+            assert!(
+                params.expected_error.is_some(),
+                "expected test to succeed, but it errored with {:?}",
+                err
+            );
+
+            assert_eq!(err, params.expected_error.unwrap());
+        }
+    }
+}
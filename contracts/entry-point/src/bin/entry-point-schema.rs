@@ -0,0 +1,10 @@
+use cosmwasm_schema::write_api;
+use skip_api_entry_point::msg::{ExecuteMsg, InstantiateMsg, MigrateMsg};
+
+fn main() {
+    write_api! {
+        instantiate: InstantiateMsg,
+        execute: ExecuteMsg,
+        migrate: MigrateMsg,
+    }
+}
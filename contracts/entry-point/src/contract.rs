@@ -0,0 +1,120 @@
+#[cfg(not(feature = "library"))]
+use cosmwasm_std::entry_point;
+use cosmwasm_std::{DepsMut, Env, MessageInfo, Reply, Response};
+use cw2::set_contract_version;
+use skip::entry_point::ExecuteMsg;
+
+use crate::{
+    error::ContractResult,
+    execute::{execute_post_swap_action, execute_swap_and_action, seed_blocked_contract_addresses},
+    msg::{InstantiateMsg, MigrateMsg},
+    reply::handle_user_swap_reply,
+    state::{BLOCKED_CONTRACT_ADDRESSES, SWAP_VENUE_MAP},
+};
+
+pub const CONTRACT_NAME: &str = "crates.io:skip-api-entry-point";
+pub const CONTRACT_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+pub const REPLY_ID_USER_SWAP: u64 = 1;
+
+// The custom query type this contract is compiled against. Defaults to the
+// chain-agnostic `Empty`; a deployment targeting a chain whose assets live
+// behind a custom bank/smart-token module swaps this for that chain SDK's
+// custom query enum (e.g. `sei_cosmwasm::SeiQueryWrapper`) behind a feature
+// flag, without touching the entry point logic below.
+#[cfg(not(feature = "sei"))]
+pub type QueryT = cosmwasm_std::Empty;
+#[cfg(feature = "sei")]
+pub type QueryT = sei_cosmwasm::SeiQueryWrapper;
+
+///////////////
+/// INSTANTIATE
+///////////////
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn instantiate(
+    deps: DepsMut<QueryT>,
+    env: Env,
+    _info: MessageInfo,
+    msg: InstantiateMsg,
+) -> ContractResult<Response> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    for swap_venue in &msg.swap_venues {
+        let adapter_contract_address = deps.api.addr_validate(&swap_venue.adapter_contract_address)?;
+        SWAP_VENUE_MAP.save(deps.storage, swap_venue.name.clone(), &adapter_contract_address)?;
+    }
+
+    seed_blocked_contract_addresses(deps, &env)?;
+
+    Ok(Response::new().add_attribute("action", "instantiate"))
+}
+
+///////////////
+/// MIGRATE
+///////////////
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn migrate(deps: DepsMut<QueryT>, env: Env, _msg: MigrateMsg) -> ContractResult<Response> {
+    set_contract_version(deps.storage, CONTRACT_NAME, CONTRACT_VERSION)?;
+
+    seed_blocked_contract_addresses(deps, &env)?;
+
+    Ok(Response::new().add_attribute("action", "migrate"))
+}
+
+///////////////
+/// EXECUTE
+///////////////
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn execute(
+    deps: DepsMut<QueryT>,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> ContractResult<Response> {
+    match msg {
+        ExecuteMsg::SwapAndAction {
+            sent_asset,
+            user_swap,
+            min_asset,
+            timeout_timestamp,
+            post_swap_action,
+            affiliates,
+        } => execute_swap_and_action(
+            deps,
+            env,
+            info,
+            sent_asset,
+            user_swap,
+            min_asset,
+            timeout_timestamp,
+            post_swap_action,
+            affiliates,
+        ),
+        ExecuteMsg::PostSwapAction {
+            min_asset,
+            post_swap_action,
+        } => execute_post_swap_action(deps, env, info, min_asset, post_swap_action),
+    }
+}
+
+///////////////
+/// REPLY
+///////////////
+
+#[cfg_attr(not(feature = "library"), entry_point)]
+pub fn reply(deps: DepsMut<QueryT>, env: Env, msg: Reply) -> ContractResult<Response> {
+    match msg.id {
+        REPLY_ID_USER_SWAP => handle_user_swap_reply(deps, env, msg),
+        id => Err(cosmwasm_std::StdError::generic_err(format!("unknown reply id: {id}")).into()),
+    }
+}
+
+pub fn blocked_contract_addresses_contains<C: cosmwasm_std::CustomQuery>(
+    deps: cosmwasm_std::Deps<C>,
+    address: &cosmwasm_std::Addr,
+) -> bool {
+    BLOCKED_CONTRACT_ADDRESSES.has(deps.storage, address.clone())
+}
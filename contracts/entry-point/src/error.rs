@@ -0,0 +1,35 @@
+use cosmwasm_std::StdError;
+use cw_utils::PaymentError;
+use thiserror::Error;
+
+pub type ContractResult<T> = Result<T, ContractError>;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ContractError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("{0}")]
+    Payment(#[from] PaymentError),
+
+    #[error("Unauthorized")]
+    Unauthorized,
+
+    #[error("Swap venue {0} not found")]
+    SwapVenueNotFound(String),
+
+    #[error("Timed out")]
+    Timeout,
+
+    #[error("Contract call address is blocked")]
+    ContractCallAddressBlocked,
+
+    #[error("Swap output received is below the requested minimum")]
+    ReceivedBelowMinimum,
+
+    #[error("{0}")]
+    Overflow(#[from] cosmwasm_std::OverflowError),
+
+    #[error("Total affiliate fees exceed the swap output")]
+    FeeExceedsOutput,
+}
@@ -0,0 +1,201 @@
+use cosmwasm_std::{
+    to_binary, BankMsg, Coin, CosmosMsg, CustomQuery, Deps, DepsMut, Empty, Env, MessageInfo,
+    Response, SubMsg, Uint128, WasmMsg,
+};
+use cw20::Cw20ExecuteMsg;
+use cw_utils::one_coin;
+use skip::{
+    asset::Asset,
+    entry_point::{Action, Affiliate, Swap},
+    swap::ExecuteMsg as SwapExecuteMsg,
+};
+
+use crate::{
+    contract::REPLY_ID_USER_SWAP,
+    error::{ContractError, ContractResult},
+    state::{PostSwapActionState, BLOCKED_CONTRACT_ADDRESSES, POST_SWAP_ACTION_STATE, SWAP_VENUE_MAP},
+};
+
+// Seed the contract-call blocklist with the adapter and entry point
+// addresses themselves, so a post-swap action can never redirect funds
+// back into the protocol's own privileged contracts.
+pub fn seed_blocked_contract_addresses<C: CustomQuery>(
+    deps: DepsMut<C>,
+    env: &Env,
+) -> ContractResult<()> {
+    BLOCKED_CONTRACT_ADDRESSES.save(deps.storage, env.contract.address.clone(), &Empty {})?;
+
+    for adapter_contract_address in SWAP_VENUE_MAP
+        .range(deps.storage, None, None, cosmwasm_std::Order::Ascending)
+        .collect::<Result<Vec<_>, _>>()?
+        .into_iter()
+        .map(|(_, address)| address)
+    {
+        BLOCKED_CONTRACT_ADDRESSES.save(deps.storage, adapter_contract_address, &Empty {})?;
+    }
+
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn execute_swap_and_action<C: CustomQuery>(
+    deps: DepsMut<C>,
+    env: Env,
+    info: MessageInfo,
+    sent_asset: Option<Asset>,
+    user_swap: Swap,
+    min_asset: Asset,
+    timeout_timestamp: u64,
+    post_swap_action: Action,
+    affiliates: Vec<Affiliate>,
+) -> ContractResult<Response> {
+    if env.block.time.seconds() > timeout_timestamp {
+        return Err(ContractError::Timeout);
+    }
+
+    let sent_coin: Coin = match sent_asset {
+        Some(asset) => match asset {
+            Asset::Native(coin) => coin,
+            Asset::Cw20(_) => return Err(ContractError::Unauthorized),
+        },
+        None => one_coin(&info)?,
+    };
+
+    let Swap::SwapExactAssetIn(swap) = user_swap;
+
+    let swap_adapter_contract_address = SWAP_VENUE_MAP
+        .load(deps.storage, swap.swap_venue_name.clone())
+        .map_err(|_| ContractError::SwapVenueNotFound(swap.swap_venue_name.clone()))?;
+
+    let user_swap_msg = SubMsg::reply_always(
+        WasmMsg::Execute {
+            contract_addr: swap_adapter_contract_address.to_string(),
+            msg: to_binary(&SwapExecuteMsg::Swap {
+                operations: swap.operations,
+            })?,
+            funds: vec![sent_coin],
+        },
+        REPLY_ID_USER_SWAP,
+    );
+
+    POST_SWAP_ACTION_STATE.save(
+        deps.storage,
+        &PostSwapActionState {
+            min_asset,
+            post_swap_action,
+            affiliates,
+        },
+    )?;
+
+    Ok(Response::new()
+        .add_submessage(user_swap_msg)
+        .add_attribute("action", "swap_and_action"))
+}
+
+// Dispatches `post_swap_action` against the given `min_asset` amount.
+// Only callable by the contract itself.
+pub fn execute_post_swap_action<C: CustomQuery>(
+    deps: DepsMut<C>,
+    env: Env,
+    info: MessageInfo,
+    min_asset: Asset,
+    post_swap_action: Action,
+) -> ContractResult<Response> {
+    if info.sender != env.contract.address {
+        return Err(ContractError::Unauthorized);
+    }
+
+    let msg = build_post_swap_action_msg(deps.as_ref(), &min_asset, post_swap_action)?;
+
+    Ok(Response::new()
+        .add_message(msg)
+        .add_attribute("action", "post_swap_action"))
+}
+
+// Turns `post_swap_action` into the `CosmosMsg` that pays it out, rejecting
+// any `ContractCall` whose target is in `BLOCKED_CONTRACT_ADDRESSES`.
+pub fn build_post_swap_action_msg<C: CustomQuery>(
+    deps: Deps<C>,
+    min_asset: &Asset,
+    post_swap_action: Action,
+) -> ContractResult<CosmosMsg> {
+    let msg = match post_swap_action {
+        Action::BankSend { to_address } => CosmosMsg::Bank(BankMsg::Send {
+            to_address,
+            amount: vec![Coin {
+                denom: min_asset.denom(),
+                amount: min_asset.amount(),
+            }],
+        }),
+        Action::ContractCall {
+            contract_address,
+            msg,
+        } => {
+            let contract_address = deps.api.addr_validate(&contract_address)?;
+
+            if crate::contract::blocked_contract_addresses_contains(deps, &contract_address) {
+                return Err(ContractError::ContractCallAddressBlocked);
+            }
+
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: contract_address.to_string(),
+                msg,
+                funds: vec![Coin {
+                    denom: min_asset.denom(),
+                    amount: min_asset.amount(),
+                }],
+            })
+        }
+    };
+
+    Ok(msg)
+}
+
+// Splits each affiliate's cut off of `asset` (`asset.amount() * basis_points_fee / 10_000`),
+// returning the remaining asset alongside the transfer messages that pay the affiliates.
+pub fn deduct_affiliate_fees(
+    asset: &Asset,
+    affiliates: &[Affiliate],
+) -> ContractResult<(Asset, Vec<CosmosMsg>)> {
+    let mut remaining_amount = asset.amount();
+    let mut msgs = Vec::with_capacity(affiliates.len());
+
+    for affiliate in affiliates {
+        let fee_amount = asset
+            .amount()
+            .checked_mul(affiliate.basis_points_fee)?
+            .checked_div(Uint128::new(10_000))
+            .unwrap();
+
+        remaining_amount = remaining_amount
+            .checked_sub(fee_amount)
+            .map_err(|_| ContractError::FeeExceedsOutput)?;
+
+        if fee_amount.is_zero() {
+            continue;
+        }
+
+        msgs.push(match asset {
+            Asset::Native(coin) => CosmosMsg::Bank(BankMsg::Send {
+                to_address: affiliate.address.clone(),
+                amount: vec![Coin {
+                    denom: coin.denom.clone(),
+                    amount: fee_amount,
+                }],
+            }),
+            Asset::Cw20(coin) => CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: coin.address.clone(),
+                msg: to_binary(&Cw20ExecuteMsg::Transfer {
+                    recipient: affiliate.address.clone(),
+                    amount: fee_amount,
+                })?,
+                funds: vec![],
+            }),
+        });
+    }
+
+    let mut remaining_asset = asset.clone();
+    remaining_asset.set_amount(remaining_amount);
+
+    Ok((remaining_asset, msgs))
+}
@@ -0,0 +1,17 @@
+use cosmwasm_schema::cw_serde;
+
+pub use skip::entry_point::ExecuteMsg;
+
+#[cw_serde]
+pub struct SwapVenue {
+    pub name: String,
+    pub adapter_contract_address: String,
+}
+
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub swap_venues: Vec<SwapVenue>,
+}
+
+#[cw_serde]
+pub struct MigrateMsg {}
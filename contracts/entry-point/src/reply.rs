@@ -0,0 +1,47 @@
+use cosmwasm_std::{CustomQuery, DepsMut, Env, Reply, Response, StdError};
+use skip::asset::Asset;
+
+use crate::{
+    error::{ContractError, ContractResult},
+    execute::{build_post_swap_action_msg, deduct_affiliate_fees},
+    state::{PostSwapActionState, POST_SWAP_ACTION_STATE},
+};
+
+// Handles the reply from the user swap submessage: the swap adapter sends
+// its output back to this contract, so the real output amount is
+// read back from this contract's own balance rather than threaded through
+// the submessage response.
+pub fn handle_user_swap_reply<C: CustomQuery>(
+    deps: DepsMut<C>,
+    env: Env,
+    msg: Reply,
+) -> ContractResult<Response> {
+    msg.result.into_result().map_err(StdError::generic_err)?;
+
+    let PostSwapActionState {
+        min_asset,
+        post_swap_action,
+        affiliates,
+    } = POST_SWAP_ACTION_STATE.load(deps.storage)?;
+    POST_SWAP_ACTION_STATE.remove(deps.storage);
+
+    let mut swap_out_asset = min_asset.clone();
+    swap_out_asset.set_amount(cosmwasm_std::Uint128::zero());
+    let balance = swap_out_asset.query_balance(&deps.querier, &env.contract.address)?;
+    swap_out_asset.set_amount(balance);
+
+    let (remaining_asset, affiliate_fee_msgs) =
+        deduct_affiliate_fees(&swap_out_asset, &affiliates)?;
+
+    if remaining_asset.amount() < min_asset.amount() {
+        return Err(ContractError::ReceivedBelowMinimum);
+    }
+
+    let post_swap_action_msg =
+        build_post_swap_action_msg(deps.as_ref(), &remaining_asset, post_swap_action)?;
+
+    Ok(Response::new()
+        .add_messages(affiliate_fee_msgs)
+        .add_message(post_swap_action_msg)
+        .add_attribute("action", "user_swap_reply"))
+}
@@ -0,0 +1,25 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Empty};
+use cw_storage_plus::{Item, Map};
+use skip::entry_point::{Action, Affiliate};
+
+// Maps a swap venue name (e.g. "astroport") to the swap adapter contract
+// that implements it.
+pub const SWAP_VENUE_MAP: Map<String, Addr> = Map::new("swap_venue_map");
+
+// Contract addresses that a `PostSwapAction::ContractCall` is never allowed
+// to target, so a swap can't be used to redirect funds into the protocol's
+// own privileged contracts.
+pub const BLOCKED_CONTRACT_ADDRESSES: Map<Addr, Empty> = Map::new("blocked_contract_addresses");
+
+// State carried from the user swap submessage to its reply, so the real
+// swap output (queried from this contract's post-swap balance) can be
+// passed on to `post_swap_action`.
+pub const POST_SWAP_ACTION_STATE: Item<PostSwapActionState> = Item::new("post_swap_action_state");
+
+#[cw_serde]
+pub struct PostSwapActionState {
+    pub min_asset: skip::asset::Asset,
+    pub post_swap_action: Action,
+    pub affiliates: Vec<Affiliate>,
+}
@@ -0,0 +1,126 @@
+use cosmwasm_std::{BankMsg, Coin, CosmosMsg, Uint128};
+use skip::{asset::Asset, entry_point::Affiliate};
+use skip_api_entry_point::{error::ContractError, execute::deduct_affiliate_fees};
+use test_case::test_case;
+
+/*
+Test Cases:
+
+Expect Success
+    - Zero affiliates
+    - Single affiliate
+    - Multiple affiliates
+
+Expect Error
+    - Affiliate fees exceed the swap output
+
+ */
+
+struct Params {
+    asset: Asset,
+    affiliates: Vec<Affiliate>,
+    expected_remaining_amount: Option<Uint128>,
+    expected_fee_amounts: Vec<Uint128>,
+    expected_error: Option<ContractError>,
+}
+
+#[test_case(
+    Params {
+        asset: Asset::Native(Coin::new(1_000_000, "uosmo")),
+        affiliates: vec![],
+        expected_remaining_amount: Some(Uint128::new(1_000_000)),
+        expected_fee_amounts: vec![],
+        expected_error: None,
+    };
+    "Zero affiliates")]
+#[test_case(
+    Params {
+        asset: Asset::Native(Coin::new(1_000_000, "uosmo")),
+        affiliates: vec![Affiliate {
+            basis_points_fee: Uint128::new(100), // 1%
+            address: "affiliate_1".to_string(),
+        }],
+        expected_remaining_amount: Some(Uint128::new(990_000)),
+        expected_fee_amounts: vec![Uint128::new(10_000)],
+        expected_error: None,
+    };
+    "Single affiliate")]
+#[test_case(
+    Params {
+        asset: Asset::Native(Coin::new(1_000_000, "uosmo")),
+        affiliates: vec![
+            Affiliate {
+                basis_points_fee: Uint128::new(100), // 1%
+                address: "affiliate_1".to_string(),
+            },
+            Affiliate {
+                basis_points_fee: Uint128::new(50), // 0.5%
+                address: "affiliate_2".to_string(),
+            },
+        ],
+        expected_remaining_amount: Some(Uint128::new(985_000)),
+        expected_fee_amounts: vec![Uint128::new(10_000), Uint128::new(5_000)],
+        expected_error: None,
+    };
+    "Multiple affiliates")]
+#[test_case(
+    Params {
+        asset: Asset::Native(Coin::new(1_000_000, "uosmo")),
+        affiliates: vec![
+            Affiliate {
+                basis_points_fee: Uint128::new(6_000), // 60%
+                address: "affiliate_1".to_string(),
+            },
+            Affiliate {
+                basis_points_fee: Uint128::new(5_000), // 50%
+                address: "affiliate_2".to_string(),
+            },
+        ],
+        expected_remaining_amount: None,
+        expected_fee_amounts: vec![],
+        expected_error: Some(ContractError::FeeExceedsOutput),
+    };
+    "Affiliate fees exceed the swap output")]
+fn test_deduct_affiliate_fees(params: Params) {
+    let res = deduct_affiliate_fees(&params.asset, &params.affiliates);
+
+    match res {
+        Ok((remaining_asset, msgs)) => {
+            assert!(
+                params.expected_error.is_none(),
+                "expected test to error with {:?}, but it succeeded",
+                params.expected_error
+            );
+
+            assert_eq!(
+                remaining_asset.amount(),
+                params.expected_remaining_amount.unwrap()
+            );
+
+            for (msg, (affiliate, fee_amount)) in msgs
+                .iter()
+                .zip(params.affiliates.iter().zip(params.expected_fee_amounts.iter()))
+            {
+                assert_eq!(
+                    *msg,
+                    CosmosMsg::Bank(BankMsg::Send {
+                        to_address: affiliate.address.clone(),
+                        amount: vec![Coin {
+                            denom: params.asset.denom(),
+                            amount: *fee_amount,
+                        }],
+                    })
+                );
+            }
+        }
+        Err(err) => {
+            assert!(
+                params.expected_error.is_some(),
+                "expected test to succeed, but it errored with {:?}",
+                err
+            );
+
+            assert_eq!(err, params.expected_error.unwrap());
+        }
+    }
+}
@@ -0,0 +1,141 @@
+use cosmwasm_std::{
+    testing::{mock_dependencies, mock_env, mock_info},
+    to_binary, Addr, BankMsg, Coin, CosmosMsg, Empty, WasmMsg,
+};
+use skip::{asset::Asset, entry_point::Action};
+use skip_api_entry_point::{
+    error::ContractError,
+    execute::execute_post_swap_action,
+    state::BLOCKED_CONTRACT_ADDRESSES,
+};
+use test_case::test_case;
+
+/*
+Test Cases:
+
+Expect Success
+    - 'BankSend' post swap action
+    - 'ContractCall' post swap action to an allowed address
+
+Expect Error
+    - 'ContractCall' post swap action to a blocked address
+    - Called by someone other than the contract itself
+
+ */
+
+struct Params {
+    caller: String,
+    blocked_addresses: Vec<String>,
+    post_swap_action: Action,
+    expected_error: Option<ContractError>,
+}
+
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        blocked_addresses: vec!["entry_point".to_string()],
+        post_swap_action: Action::BankSend {
+            to_address: "user".to_string(),
+        },
+        expected_error: None,
+    };
+    "BankSend post swap action")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        blocked_addresses: vec!["entry_point".to_string()],
+        post_swap_action: Action::ContractCall {
+            contract_address: "allowed_contract".to_string(),
+            msg: to_binary(&Empty {}).unwrap(),
+        },
+        expected_error: None,
+    };
+    "ContractCall post swap action to an allowed address")]
+#[test_case(
+    Params {
+        caller: "entry_point".to_string(),
+        blocked_addresses: vec!["entry_point".to_string(), "swap_contract".to_string()],
+        post_swap_action: Action::ContractCall {
+            contract_address: "swap_contract".to_string(),
+            msg: to_binary(&Empty {}).unwrap(),
+        },
+        expected_error: Some(ContractError::ContractCallAddressBlocked),
+    };
+    "ContractCall post swap action to a blocked address")]
+#[test_case(
+    Params {
+        caller: "random".to_string(),
+        blocked_addresses: vec!["entry_point".to_string()],
+        post_swap_action: Action::BankSend {
+            to_address: "user".to_string(),
+        },
+        expected_error: Some(ContractError::Unauthorized),
+    };
+    "Unauthorized caller")]
+fn test_execute_post_swap_action(params: Params) {
+    let mut deps = mock_dependencies();
+
+    let mut env = mock_env();
+    env.contract.address = Addr::unchecked("entry_point");
+
+    for address in &params.blocked_addresses {
+        BLOCKED_CONTRACT_ADDRESSES
+            .save(deps.as_mut().storage, Addr::unchecked(address), &Empty {})
+            .unwrap();
+    }
+
+    let info = mock_info(&params.caller, &[]);
+
+    let res = execute_post_swap_action(
+        deps.as_mut(),
+        env,
+        info,
+        Asset::Native(Coin::new(100, "uosmo")),
+        params.post_swap_action.clone(),
+    );
+
+    match res {
+        Ok(res) => {
+            assert!(
+                params.expected_error.is_none(),
+                "expected test to error with {:?}, but it succeeded",
+                params.expected_error
+            );
+
+            match params.post_swap_action {
+                Action::BankSend { to_address } => {
+                    assert_eq!(
+                        res.messages[0].msg,
+                        CosmosMsg::Bank(BankMsg::Send {
+                            to_address,
+                            amount: vec![Coin::new(100, "uosmo")],
+                        })
+                    );
+                }
+                Action::ContractCall {
+                    contract_address,
+                    msg,
+                } => {
+                    assert_eq!(
+                        res.messages[0].msg,
+                        CosmosMsg::Wasm(WasmMsg::Execute {
+                            contract_addr: contract_address,
+                            msg,
+                            funds: vec![Coin::new(100, "uosmo")],
+                        })
+                    );
+                }
+            }
+        }
+        Err(err) => {
+            assert!(
+                params.expected_error.is_some(),
+                "expected test to succeed, but it errored with {:?}",
+                err
+            );
+
+            assert_eq!(err, params.expected_error.unwrap());
+        }
+    }
+}
+
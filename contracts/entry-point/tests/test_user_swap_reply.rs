@@ -0,0 +1,78 @@
+use cosmwasm_std::{
+    testing::{mock_dependencies, mock_env},
+    Addr, Reply, SubMsgResponse, SubMsgResult, Uint128,
+};
+use skip::{
+    asset::Asset,
+    entry_point::{Action, Affiliate},
+};
+use skip_api_entry_point::{
+    contract::REPLY_ID_USER_SWAP,
+    error::ContractError,
+    reply::handle_user_swap_reply,
+    state::{PostSwapActionState, POST_SWAP_ACTION_STATE},
+};
+use test_case::test_case;
+
+/*
+Test Cases:
+
+Expect Error
+    - Affiliate fees leave the user below their requested `min_asset`
+
+ */
+
+#[test_case(
+    Uint128::new(1_000_000),
+    vec![Affiliate {
+        basis_points_fee: Uint128::new(500), // 5%
+        address: "affiliate_1".to_string(),
+    }],
+    Uint128::new(960_000);
+    "Affiliate fees push the user below min_asset")]
+fn test_handle_user_swap_reply_received_below_minimum(
+    contract_balance: Uint128,
+    affiliates: Vec<Affiliate>,
+    min_asset_amount: Uint128,
+) {
+    let mut deps = mock_dependencies();
+
+    let mut env = mock_env();
+    env.contract.address = Addr::unchecked("entry_point");
+
+    deps.querier
+        .update_balance("entry_point", vec![cosmwasm_std::Coin::new(
+            contract_balance.u128(),
+            "uosmo",
+        )]);
+
+    POST_SWAP_ACTION_STATE
+        .save(
+            deps.as_mut().storage,
+            &PostSwapActionState {
+                min_asset: Asset::Native(cosmwasm_std::Coin::new(
+                    min_asset_amount.u128(),
+                    "uosmo",
+                )),
+                post_swap_action: Action::BankSend {
+                    to_address: "user".to_string(),
+                },
+                affiliates,
+            },
+        )
+        .unwrap();
+
+    let res = handle_user_swap_reply(
+        deps.as_mut(),
+        env,
+        Reply {
+            id: REPLY_ID_USER_SWAP,
+            result: SubMsgResult::Ok(SubMsgResponse {
+                events: vec![],
+                data: None,
+            }),
+        },
+    );
+
+    assert_eq!(res.unwrap_err(), ContractError::ReceivedBelowMinimum);
+}
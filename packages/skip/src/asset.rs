@@ -0,0 +1,72 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Addr, Coin, CustomQuery, QuerierWrapper, StdResult, Uint128};
+use cw20::{BalanceResponse, Cw20Coin, Cw20QueryMsg};
+
+/// Asset is a wrapper enum around either a native [`Coin`] or a [`Cw20Coin`],
+/// allowing contracts to treat both in the same way when sizing and
+/// transferring swap inputs/outputs.
+#[cw_serde]
+pub enum Asset {
+    Native(Coin),
+    Cw20(Cw20Coin),
+}
+
+impl Asset {
+    pub fn denom(&self) -> String {
+        match self {
+            Asset::Native(coin) => coin.denom.clone(),
+            Asset::Cw20(coin) => coin.address.clone(),
+        }
+    }
+
+    pub fn amount(&self) -> Uint128 {
+        match self {
+            Asset::Native(coin) => coin.amount,
+            Asset::Cw20(coin) => coin.amount,
+        }
+    }
+
+    pub fn set_amount(&mut self, amount: Uint128) {
+        match self {
+            Asset::Native(coin) => coin.amount = amount,
+            Asset::Cw20(coin) => coin.amount = amount,
+        }
+    }
+
+    /// Resolves `address`'s balance of this asset's denom, routing native
+    /// coins through the standard bank query and cw20 coins through the
+    /// token contract's own `Balance` query. Generic over the chain's
+    /// custom query type so the same adapter code works whether balances
+    /// are backed by plain bank coins or a chain-specific token module.
+    pub fn query_balance<C: CustomQuery>(
+        &self,
+        querier: &QuerierWrapper<C>,
+        address: &Addr,
+    ) -> StdResult<Uint128> {
+        match self {
+            Asset::Native(coin) => Ok(querier.query_balance(address, &coin.denom)?.amount),
+            Asset::Cw20(coin) => {
+                let response: BalanceResponse = querier.query_wasm_smart(
+                    &coin.address,
+                    &Cw20QueryMsg::Balance {
+                        address: address.to_string(),
+                    },
+                )?;
+
+                Ok(response.balance)
+            }
+        }
+    }
+}
+
+impl From<Coin> for Asset {
+    fn from(coin: Coin) -> Self {
+        Asset::Native(coin)
+    }
+}
+
+impl From<Cw20Coin> for Asset {
+    fn from(coin: Cw20Coin) -> Self {
+        Asset::Cw20(coin)
+    }
+}
@@ -0,0 +1,54 @@
+use cosmwasm_schema::cw_serde;
+use cosmwasm_std::{Binary, Uint128};
+
+use crate::{asset::Asset, swap::SwapOperation};
+
+/// A cut of the post-swap output paid to `address`, expressed in basis
+/// points (1/100th of a percent) of the resolved output amount.
+#[cw_serde]
+pub struct Affiliate {
+    pub basis_points_fee: Uint128,
+    pub address: String,
+}
+
+#[cw_serde]
+pub struct SwapExactAssetIn {
+    pub swap_venue_name: String,
+    pub operations: Vec<SwapOperation>,
+}
+
+#[cw_serde]
+pub enum Swap {
+    SwapExactAssetIn(SwapExactAssetIn),
+}
+
+/// The action to take once the post-swap asset is in hand.
+#[cw_serde]
+pub enum Action {
+    BankSend {
+        to_address: String,
+    },
+    ContractCall {
+        contract_address: String,
+        msg: Binary,
+    },
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    SwapAndAction {
+        sent_asset: Option<Asset>,
+        user_swap: Swap,
+        min_asset: Asset,
+        timeout_timestamp: u64,
+        post_swap_action: Action,
+        affiliates: Vec<Affiliate>,
+    },
+    // Only accepted from the contract itself; dispatches `post_swap_action`
+    // for `min_asset`. The user swap reply builds its post-swap message
+    // directly and does not send this message.
+    PostSwapAction {
+        min_asset: Asset,
+        post_swap_action: Action,
+    },
+}
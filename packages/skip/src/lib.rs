@@ -0,0 +1,3 @@
+pub mod asset;
+pub mod entry_point;
+pub mod swap;
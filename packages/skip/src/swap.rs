@@ -0,0 +1,64 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::Addr;
+
+use crate::asset::Asset;
+
+/// Instantiate message shared by swap adapters that sit directly on top of
+/// an Astroport router/pair.
+#[cw_serde]
+pub struct AstroportInstantiateMsg {
+    pub entry_point_contract_address: String,
+    pub router_contract_address: String,
+}
+
+/// Minimal instantiate message for swap adapters that only need to know the
+/// entry point contract. The Lido Satellite adapter defines its own
+/// `InstantiateMsg` instead, since it also stores its denoms.
+#[cw_serde]
+pub struct InstantiateMsg {
+    pub entry_point_contract_address: String,
+}
+
+#[cw_serde]
+pub enum ExecuteMsg {
+    Swap {
+        operations: Vec<SwapOperation>,
+    },
+    // Exact-output swap: the caller wants exactly `asset_out` and has sent
+    // funds for the input amount computed from a prior
+    // `QueryMsg::SimulateSwapExactAssetOut` call. Only adapters that can
+    // size an exact-output route (e.g. Astroport, via reverse simulation)
+    // support this variant.
+    SwapExactAssetOut {
+        operations: Vec<SwapOperation>,
+        asset_out: Asset,
+    },
+    TransferFundsBack {
+        swapper: Addr,
+        return_denom: String,
+    },
+}
+
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {
+    #[returns(Asset)]
+    SimulateSwapExactAssetOut {
+        asset_out: Asset,
+        swap_operations: Vec<SwapOperation>,
+    },
+    #[returns(Asset)]
+    SimulateSwapExactAssetIn {
+        asset_in: Asset,
+        swap_operations: Vec<SwapOperation>,
+    },
+}
+
+/// A single hop of a swap route, identifying the pool contract and the
+/// denoms being traded through it.
+#[cw_serde]
+pub struct SwapOperation {
+    pub pool: String,
+    pub denom_in: String,
+    pub denom_out: String,
+}